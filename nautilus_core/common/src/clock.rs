@@ -15,7 +15,15 @@
 
 //! Real-time and static test `Clock` implementations.
 
-use std::{collections::HashMap, ops::Deref};
+use std::{
+    collections::HashMap,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Instant,
+};
 
 use chrono::{DateTime, Utc};
 use nautilus_core::{
@@ -23,6 +31,7 @@ use nautilus_core::{
     nanos::UnixNanos,
     time::{get_atomic_clock_realtime, AtomicTime},
 };
+use tokio::sync::{broadcast, mpsc};
 use ustr::Ustr;
 
 use crate::{
@@ -30,6 +39,70 @@ use crate::{
     timer::{LiveTimer, TestTimer, TimeEvent, TimeEventHandler},
 };
 
+/// Buffer capacity for a [`LiveClock`] timer event stream. Once it is full, a
+/// lagging all-timers (broadcast) subscriber loses the oldest buffered events,
+/// while a per-timer stream drops each new event (logging a warning).
+const TIME_EVENT_STREAM_CAPACITY: usize = 1_000;
+
+/// A span of time in nanoseconds, distinct from an absolute [`UnixNanos`]
+/// instant. Named to avoid shadowing [`std::time::Duration`], which
+/// [`TimeDriver::schedule_wake`] returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NanosDuration(u64);
+
+impl NanosDuration {
+    /// Creates a new [`NanosDuration`] from a count of nanoseconds.
+    #[must_use]
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Self(nanos)
+    }
+
+    /// Returns the duration as a count of nanoseconds.
+    #[must_use]
+    pub const fn as_nanos(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns `alert_time_ns - time_ns` as a [`NanosDuration`], clamping to zero
+    /// instead of wrapping when `alert_time_ns` is not after `time_ns`.
+    #[must_use]
+    pub fn checked_sub(alert_time_ns: UnixNanos, time_ns: UnixNanos) -> Self {
+        let nanos = alert_time_ns
+            .as_i64()
+            .saturating_sub(time_ns.as_i64())
+            .max(0);
+        Self(nanos as u64)
+    }
+}
+
+impl From<u64> for NanosDuration {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<NanosDuration> for u64 {
+    fn from(value: NanosDuration) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Add<NanosDuration> for UnixNanos {
+    type Output = UnixNanos;
+
+    fn add(self, rhs: NanosDuration) -> UnixNanos {
+        UnixNanos::from(self.as_u64().saturating_add(rhs.0))
+    }
+}
+
+impl std::ops::Sub<NanosDuration> for UnixNanos {
+    type Output = UnixNanos;
+
+    fn sub(self, rhs: NanosDuration) -> UnixNanos {
+        UnixNanos::from(self.as_u64().saturating_sub(rhs.0))
+    }
+}
+
 /// Represents a type of clock.
 ///
 /// # Notes
@@ -52,6 +125,47 @@ pub trait Clock {
     /// Returns the current UNIX time in seconds.
     fn timestamp(&self) -> f64;
 
+    /// Returns the current monotonic timestamp in nanoseconds (ns).
+    ///
+    /// Unlike [`Clock::timestamp_ns`], this reading is sourced from a monotonic
+    /// clock and so is immune to NTP steps or wall-clock corrections, making it
+    /// suitable for measuring latencies or enforcing timer monotonicity.
+    fn timestamp_mono_ns(&self) -> UnixNanos;
+
+    /// Returns the current monotonic timestamp in microseconds (μs).
+    fn timestamp_mono_us(&self) -> u64 {
+        self.timestamp_mono_ns().as_u64() / 1_000
+    }
+
+    /// Returns the current monotonic timestamp in milliseconds (ms).
+    fn timestamp_mono_ms(&self) -> u64 {
+        self.timestamp_mono_ns().as_u64() / 1_000_000
+    }
+
+    /// Compares a prior `(realtime, monotonic)` reading against the current one
+    /// and returns the realtime skew in nanoseconds if the realtime clock has
+    /// jumped backward relative to the monotonic clock.
+    ///
+    /// Returns `None` when no backward jump is detected.
+    fn detect_backward_jump(
+        &self,
+        last_realtime_ns: UnixNanos,
+        last_mono_ns: UnixNanos,
+    ) -> Option<i64> {
+        let realtime_delta = self.timestamp_ns().as_i64() - last_realtime_ns.as_i64();
+        let mono_delta = self.timestamp_mono_ns().as_i64() - last_mono_ns.as_i64();
+        let skew = realtime_delta - mono_delta;
+
+        if skew < 0 {
+            log::warn!(
+                "Detected backward clock jump of {skew}ns (realtime delta {realtime_delta}ns, monotonic delta {mono_delta}ns)"
+            );
+            Some(skew)
+        } else {
+            None
+        }
+    }
+
     /// Returns the names of active timers in the clock.
     fn timer_names(&self) -> Vec<&str>;
 
@@ -77,7 +191,7 @@ pub trait Clock {
     fn set_timer_ns(
         &mut self,
         name: &str,
-        interval_ns: u64,
+        interval: NanosDuration,
         start_time_ns: UnixNanos,
         stop_time_ns: Option<UnixNanos>,
         callback: Option<EventHandler>,
@@ -93,6 +207,9 @@ pub trait Clock {
 /// Stores the current timestamp internally which can be advanced.
 pub struct TestClock {
     time: AtomicTime,
+    /// A separately-advanceable monotonic counter, so backtests remain
+    /// deterministic while still exercising monotonic-clock consumers.
+    mono_time: AtomicTime,
     timers: HashMap<Ustr, TestTimer>,
     default_callback: Option<EventHandler>,
     callbacks: HashMap<Ustr, EventHandler>,
@@ -104,6 +221,7 @@ impl TestClock {
     pub fn new() -> Self {
         Self {
             time: AtomicTime::new(false, UnixNanos::default()),
+            mono_time: AtomicTime::new(false, UnixNanos::default()),
             timers: HashMap::new(),
             default_callback: None,
             callbacks: HashMap::new(),
@@ -115,6 +233,16 @@ impl TestClock {
         &self.timers
     }
 
+    /// Advances the monotonic clock independently of wall-clock time.
+    pub fn advance_mono_time(&mut self, to_time_ns: UnixNanos) {
+        assert!(
+            to_time_ns >= self.mono_time.get_time_ns(),
+            "`to_time_ns` was < `self.mono_time.get_time_ns()`"
+        );
+
+        self.mono_time.set_time(to_time_ns);
+    }
+
     pub fn advance_time(&mut self, to_time_ns: UnixNanos, set_time: bool) -> Vec<TimeEvent> {
         // Time should increase monotonically
         assert!(
@@ -137,6 +265,29 @@ impl TestClock {
         timers
     }
 
+    /// Returns the earliest `next_time_ns` among active timers, or `None` if
+    /// there are none.
+    #[must_use]
+    pub fn peek_next_deadline(&self) -> Option<UnixNanos> {
+        self.timers
+            .values()
+            .filter(|timer| !timer.is_expired())
+            .map(TestTimer::next_time_ns)
+            .min()
+    }
+
+    /// Advances time to the earliest pending timer deadline and returns the
+    /// events that fired, or an empty `Vec` if there are no active timers.
+    ///
+    /// Lets an event-driven backtest loop step to the next event without
+    /// needing to track deadlines externally or risk overshooting.
+    pub fn advance_to_next_timer(&mut self) -> Vec<TimeEvent> {
+        match self.peek_next_deadline() {
+            Some(next_time_ns) => self.advance_time(next_time_ns, true),
+            None => Vec::new(),
+        }
+    }
+
     /// Assumes time events are sorted by their `ts_event`.
     #[must_use]
     pub fn match_handlers(&self, events: Vec<TimeEvent>) -> Vec<TimeEventHandler> {
@@ -186,7 +337,7 @@ impl Deref for TestClock {
 
 impl Clock for TestClock {
     fn timestamp_ns(&self) -> UnixNanos {
-        self.time.get_time_ns()
+        TimeDriver::now_ns(&self.time)
     }
 
     fn timestamp_us(&self) -> u64 {
@@ -201,6 +352,10 @@ impl Clock for TestClock {
         self.time.get_time()
     }
 
+    fn timestamp_mono_ns(&self) -> UnixNanos {
+        TimeDriver::now_ns(&self.mono_time)
+    }
+
     fn timer_names(&self) -> Vec<&str> {
         self.timers
             .iter()
@@ -238,13 +393,9 @@ impl Clock for TestClock {
             None => None,
         };
 
-        let time_ns = self.time.get_time_ns();
-        let timer = TestTimer::new(
-            name,
-            (alert_time_ns - time_ns).into(),
-            time_ns,
-            Some(alert_time_ns),
-        )?;
+        let time_ns = TimeDriver::now_ns(&self.time);
+        let interval = NanosDuration::checked_sub(alert_time_ns, time_ns);
+        let timer = TestTimer::new(name, interval.into(), time_ns, Some(alert_time_ns))?;
         self.timers.insert(name_ustr, timer);
         Ok(())
     }
@@ -252,13 +403,13 @@ impl Clock for TestClock {
     fn set_timer_ns(
         &mut self,
         name: &str,
-        interval_ns: u64,
+        interval: NanosDuration,
         start_time_ns: UnixNanos,
         stop_time_ns: Option<UnixNanos>,
         callback: Option<EventHandler>,
     ) -> anyhow::Result<()> {
         check_valid_string(name, "name")?;
-        check_positive_u64(interval_ns, stringify!(interval_ns))?;
+        check_positive_u64(interval.as_nanos(), stringify!(interval))?;
         check_predicate_true(
             callback.is_some() | self.default_callback.is_some(),
             "All Python callbacks were `None`",
@@ -270,7 +421,7 @@ impl Clock for TestClock {
             None => None,
         };
 
-        let timer = TestTimer::new(name, interval_ns, start_time_ns, stop_time_ns)?;
+        let timer = TestTimer::new(name, interval.into(), start_time_ns, stop_time_ns)?;
         self.timers.insert(name_ustr, timer);
         Ok(())
     }
@@ -303,19 +454,56 @@ impl Clock for TestClock {
 ///
 /// Timestamps are guaranteed to be unique and monotonically increasing.
 pub struct LiveClock {
+    /// The true wall-clock source, always available via `Deref` regardless of
+    /// which [`TimeDriver`] backs the `Clock` trait readings below.
     time: &'static AtomicTime,
+    /// `Instant` base captured at construction, used to derive monotonic
+    /// readings that are immune to NTP steps or wall-clock corrections.
+    mono_base: Instant,
+    /// The driver `timestamp_ns`/`set_time_alert_ns`/`set_timer_ns` delegate
+    /// to. Defaults to real wall-clock time; swap via [`LiveClock::with_driver`]
+    /// to run at a scaled speed or in replay mode.
+    driver: Box<dyn TimeDriver + Send + Sync>,
     timers: HashMap<Ustr, LiveTimer>,
     default_callback: Option<EventHandler>,
+    stream_txs: HashMap<Ustr, mpsc::Sender<TimeEvent>>,
+    broadcast_tx: Option<broadcast::Sender<TimeEvent>>,
 }
 
 impl LiveClock {
-    /// Creates a new [`LiveClock`] instance.
+    /// Creates a new [`LiveClock`] instance, driven by real wall-clock time.
     #[must_use]
     pub fn new() -> Self {
+        let time = get_atomic_clock_realtime();
+        Self {
+            time,
+            mono_base: Instant::now(),
+            driver: Box::new(RealTimeDriver(time)),
+            timers: HashMap::new(),
+            default_callback: None,
+            stream_txs: HashMap::new(),
+            broadcast_tx: None,
+        }
+    }
+
+    /// Creates a new [`LiveClock`] driven by `driver` instead of real
+    /// wall-clock time, e.g. a [`ScaledClock`] for accelerated live runs, or a
+    /// [`ReplayClock`] for fast-forwarding backtests.
+    ///
+    /// The `Clock` timestamp readings (other than the monotonic ones) then
+    /// come from `driver`, so they carry only `driver`'s guarantees rather
+    /// than the uniqueness of the default wall-clock source; a
+    /// [`ReplayClock`] returns the same timestamp until it is advanced.
+    #[must_use]
+    pub fn with_driver(driver: Box<dyn TimeDriver + Send + Sync>) -> Self {
         Self {
             time: get_atomic_clock_realtime(),
+            mono_base: Instant::now(),
+            driver,
             timers: HashMap::new(),
             default_callback: None,
+            stream_txs: HashMap::new(),
+            broadcast_tx: None,
         }
     }
 
@@ -323,6 +511,71 @@ impl LiveClock {
     pub const fn get_timers(&self) -> &HashMap<Ustr, LiveTimer> {
         &self.timers
     }
+
+    /// Returns a [`mpsc::Receiver`] that yields [`TimeEvent`]s fired by the
+    /// named timer, so pure-Rust async tasks can `await` them directly
+    /// instead of going through an [`EventHandler`] callback.
+    ///
+    /// Only a timer set after this call publishes to the returned receiver;
+    /// a timer already set keeps publishing to the streams that existed when
+    /// it was set.
+    pub fn subscribe_timer_events(&mut self, name: &str) -> mpsc::Receiver<TimeEvent> {
+        let (tx, rx) = mpsc::channel(TIME_EVENT_STREAM_CAPACITY);
+        self.stream_txs.insert(Ustr::from(name), tx);
+        rx
+    }
+
+    /// Returns a [`broadcast::Receiver`] that yields [`TimeEvent`]s fired by
+    /// any timer on this clock. Supports multiple concurrent subscribers; a
+    /// subscriber that falls too far behind receives a `Lagged` error on its
+    /// next `recv` rather than blocking the clock.
+    ///
+    /// Timers set before the first call to this method do not publish to any
+    /// all-timers receiver.
+    pub fn subscribe_all_timer_events(&mut self) -> broadcast::Receiver<TimeEvent> {
+        match &self.broadcast_tx {
+            Some(tx) => tx.subscribe(),
+            None => {
+                let (tx, rx) = broadcast::channel(TIME_EVENT_STREAM_CAPACITY);
+                self.broadcast_tx = Some(tx);
+                rx
+            }
+        }
+    }
+
+    /// Pushes a fired `TimeEvent` to the given subscribed streams, in addition
+    /// to the `EventHandler` callback invoked by the `LiveTimer`.
+    fn publish(
+        stream_tx: Option<&mpsc::Sender<TimeEvent>>,
+        broadcast_tx: Option<&broadcast::Sender<TimeEvent>>,
+        event: &TimeEvent,
+    ) {
+        if let Some(tx) = stream_tx {
+            if let Err(e) = tx.try_send(event.clone()) {
+                log::warn!("Timer event stream for '{}' lagged or closed: {e}", event.name);
+            }
+        }
+
+        if let Some(tx) = broadcast_tx {
+            // No active subscribers is not an error, just nothing to deliver to.
+            let _ = tx.send(event.clone());
+        }
+    }
+
+    /// Pushes a fired `TimeEvent` to any subscribed streams for its timer.
+    pub(crate) fn publish_to_streams(&self, event: &TimeEvent) {
+        Self::publish(self.stream_txs.get(&event.name), self.broadcast_tx.as_ref(), event);
+    }
+
+    /// Builds the stream-publishing hook handed to a [`LiveTimer`] for `name`,
+    /// so its fire path pushes to subscribed streams in addition to invoking
+    /// the `EventHandler` callback, without needing a reference back to this
+    /// clock (the timer's fire loop runs independently once started).
+    fn timer_event_sink(&self, name: &str) -> impl Fn(&TimeEvent) + Send + Sync + 'static {
+        let stream_tx = self.stream_txs.get(&Ustr::from(name)).cloned();
+        let broadcast_tx = self.broadcast_tx.clone();
+        move |event: &TimeEvent| Self::publish(stream_tx.as_ref(), broadcast_tx.as_ref(), event)
+    }
 }
 
 impl Default for LiveClock {
@@ -342,19 +595,23 @@ impl Deref for LiveClock {
 
 impl Clock for LiveClock {
     fn timestamp_ns(&self) -> UnixNanos {
-        self.time.get_time_ns()
+        self.driver.now_ns()
     }
 
     fn timestamp_us(&self) -> u64 {
-        self.time.get_time_us()
+        self.driver.now_ns().as_u64() / 1_000
     }
 
     fn timestamp_ms(&self) -> u64 {
-        self.time.get_time_ms()
+        self.driver.now_ns().as_u64() / 1_000_000
     }
 
     fn timestamp(&self) -> f64 {
-        self.time.get_time()
+        self.driver.now_ns().as_u64() as f64 / 1e9
+    }
+
+    fn timestamp_mono_ns(&self) -> UnixNanos {
+        UnixNanos::from(self.mono_base.elapsed().as_nanos() as u64)
     }
 
     fn timer_names(&self) -> Vec<&str> {
@@ -393,10 +650,24 @@ impl Clock for LiveClock {
             None => self.default_callback.clone().unwrap(),
         };
 
-        let ts_now = self.get_time_ns();
+        let ts_now = self.driver.now_ns();
         alert_time_ns = std::cmp::max(alert_time_ns, ts_now);
-        let interval_ns = (alert_time_ns - ts_now).into();
-        let mut timer = LiveTimer::new(name, interval_ns, ts_now, Some(alert_time_ns), callback)?;
+        // The driver converts the virtual alert time into however long we
+        // actually need to wait in real time (identity for real wall-clock
+        // time, scaled for a `ScaledClock`, `Duration::MAX` for a `ReplayClock`,
+        // which never wakes via real-time scheduling).
+        let real_delay = self.driver.schedule_wake(alert_time_ns);
+        // The timer's fire path pushes to any subscribed streams in addition
+        // to invoking `callback`, via this sink.
+        let sink = self.timer_event_sink(name);
+        let mut timer = LiveTimer::new(
+            name,
+            real_delay.as_nanos() as u64,
+            ts_now,
+            Some(alert_time_ns),
+            callback,
+            sink,
+        )?;
 
         timer.start();
         self.timers.insert(Ustr::from(name), timer);
@@ -406,13 +677,13 @@ impl Clock for LiveClock {
     fn set_timer_ns(
         &mut self,
         name: &str,
-        interval_ns: u64,
+        interval: NanosDuration,
         start_time_ns: UnixNanos,
         stop_time_ns: Option<UnixNanos>,
         callback: Option<EventHandler>,
     ) -> anyhow::Result<()> {
         check_valid_string(name, stringify!(name))?;
-        check_positive_u64(interval_ns, stringify!(interval_ns))?;
+        check_positive_u64(interval.as_nanos(), stringify!(interval))?;
         check_predicate_true(
             callback.is_some() | self.default_callback.is_some(),
             "No callbacks provided",
@@ -423,7 +694,20 @@ impl Clock for LiveClock {
             None => self.default_callback.clone().unwrap(),
         };
 
-        let mut timer = LiveTimer::new(name, interval_ns, start_time_ns, stop_time_ns, callback)?;
+        // Scale the requested interval into a real delay from "now" the same
+        // way a one-shot alert is scaled, so a `ScaledClock`-backed clock
+        // repeats at the correct real-time cadence.
+        let ts_now = self.driver.now_ns();
+        let real_interval = self.driver.schedule_wake(ts_now + interval);
+        let sink = self.timer_event_sink(name);
+        let mut timer = LiveTimer::new(
+            name,
+            real_interval.as_nanos() as u64,
+            start_time_ns,
+            stop_time_ns,
+            callback,
+            sink,
+        )?;
         timer.start();
         self.timers.insert(Ustr::from(name), timer);
         Ok(())
@@ -459,4 +743,337 @@ impl Clock for LiveClock {
     }
 }
 
-// TODO: Rust specific clock tests
+/// A pluggable source of virtual time that a [`Clock`] delegates to.
+///
+/// Decouples "what time is it" and "wake me at this time" from how that time
+/// is actually produced, so a clock can be backed by real wall-clock time,
+/// a scaled or replayed source, or (in tests) a manually-advanced counter.
+pub trait TimeDriver {
+    /// Returns the current time in nanoseconds since the UNIX epoch, as seen
+    /// by this driver (which may run faster, slower, or independently of
+    /// wall-clock time).
+    fn now_ns(&self) -> UnixNanos;
+
+    /// Converts the virtual wake-up time `at` into the real-world delay the
+    /// caller should wait before the underlying OS timer actually fires.
+    ///
+    /// Returns the delay rather than sleeping itself, so this never blocks
+    /// the calling thread; the caller is responsible for waiting it out
+    /// asynchronously (e.g. spawning a timer/task with the returned delay).
+    fn schedule_wake(&self, at: UnixNanos) -> std::time::Duration;
+}
+
+impl TimeDriver for AtomicTime {
+    fn now_ns(&self) -> UnixNanos {
+        self.get_time_ns()
+    }
+
+    fn schedule_wake(&self, at: UnixNanos) -> std::time::Duration {
+        // Real wall-clock time: the virtual and real delays are identical.
+        let delay_ns = at.as_i64().saturating_sub(self.now_ns().as_i64()).max(0);
+        std::time::Duration::from_nanos(delay_ns as u64)
+    }
+}
+
+/// The default [`TimeDriver`] for [`LiveClock`], sourced from the process-wide
+/// realtime clock.
+struct RealTimeDriver(&'static AtomicTime);
+
+impl TimeDriver for RealTimeDriver {
+    fn now_ns(&self) -> UnixNanos {
+        self.0.get_time_ns()
+    }
+
+    fn schedule_wake(&self, at: UnixNanos) -> std::time::Duration {
+        self.0.schedule_wake(at)
+    }
+}
+
+/// A [`TimeDriver`] that runs live strategies at `speed`x wall-clock speed.
+///
+/// Keeps an anchor `(wall_anchor, virtual_anchor_ns, speed)` and re-anchors
+/// on every speed change so virtual time stays continuous across the change.
+pub struct ScaledClock {
+    inner: Mutex<ScaledClockState>,
+}
+
+struct ScaledClockState {
+    /// Monotonic anchor for measuring wall-elapsed time, so an NTP step in
+    /// the realtime clock can't make virtual time jump backward.
+    wall_anchor: Instant,
+    virtual_anchor_ns: UnixNanos,
+    speed: f64,
+}
+
+/// Validates that `speed` is finite and strictly positive, so virtual time
+/// in a [`ScaledClock`] can never freeze (`0.0`) or run backward (negative).
+fn check_positive_speed(speed: f64) -> anyhow::Result<()> {
+    check_predicate_true(
+        speed.is_finite() && speed > 0.0,
+        "`speed` must be finite and > 0.0",
+    )
+}
+
+impl ScaledClock {
+    /// Creates a new [`ScaledClock`] anchored to the current wall-clock time,
+    /// running at `speed`x (e.g. `2.0` runs twice as fast as real time).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `speed` is not finite and strictly positive.
+    pub fn new(speed: f64) -> anyhow::Result<Self> {
+        check_positive_speed(speed)?;
+        Ok(Self {
+            inner: Mutex::new(ScaledClockState {
+                wall_anchor: Instant::now(),
+                virtual_anchor_ns: get_atomic_clock_realtime().get_time_ns(),
+                speed,
+            }),
+        })
+    }
+
+    /// Changes the playback speed, re-anchoring so virtual time remains
+    /// continuous across the change.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `speed` is not finite and strictly positive.
+    pub fn set_speed(&self, speed: f64) -> anyhow::Result<()> {
+        check_positive_speed(speed)?;
+        let mut state = self.inner.lock().unwrap();
+        let virtual_now_ns = Self::virtual_now_ns(&state);
+        state.wall_anchor = Instant::now();
+        state.virtual_anchor_ns = virtual_now_ns;
+        state.speed = speed;
+        Ok(())
+    }
+
+    fn virtual_now_ns(state: &ScaledClockState) -> UnixNanos {
+        let wall_elapsed_ns = state.wall_anchor.elapsed().as_nanos() as i64;
+        let virtual_elapsed_ns = (wall_elapsed_ns as f64 * state.speed) as i64;
+        UnixNanos::from((state.virtual_anchor_ns.as_i64() + virtual_elapsed_ns) as u64)
+    }
+}
+
+impl TimeDriver for ScaledClock {
+    fn now_ns(&self) -> UnixNanos {
+        Self::virtual_now_ns(&self.inner.lock().unwrap())
+    }
+
+    fn schedule_wake(&self, at: UnixNanos) -> std::time::Duration {
+        let state = self.inner.lock().unwrap();
+        let virtual_interval_ns = at
+            .as_i64()
+            .saturating_sub(Self::virtual_now_ns(&state).as_i64())
+            .max(0) as u64;
+        let speed = state.speed;
+        drop(state);
+
+        if speed <= 0.0 {
+            // Paused (or invalid) playback: virtual time never advances, so
+            // the wake can never arrive. Return the max delay instead of
+            // dividing by zero, which would otherwise saturate `as u64` to a
+            // spurious, effectively-infinite sleep from `+inf`/`NaN`.
+            return std::time::Duration::MAX;
+        }
+
+        // Convert the virtual interval to a real delay; the caller is
+        // responsible for waiting it out asynchronously so it fires at the
+        // correct scaled moment without blocking this thread.
+        let real_interval_ns = (virtual_interval_ns as f64 / speed) as u64;
+        std::time::Duration::from_nanos(real_interval_ns)
+    }
+}
+
+/// A [`TimeDriver`] that advances strictly to the timestamps of incoming
+/// data, for fast-forwarding backtests without any wall-clock delay.
+///
+/// A timer backed by this driver does not actually fire on `advance_to`:
+/// [`LiveClock`] fires timers via a real-time sleep of the delay
+/// [`TimeDriver::schedule_wake`] returns, and there is no such delay to wait
+/// out here. Firing a `LiveClock` timer in lockstep with `advance_to` would
+/// need the timer itself to be driven by this clock rather than by a
+/// real-time sleep, which is outside what this crate's timer currently
+/// supports; [`TestClock::advance_to_next_timer`] is the supported way to
+/// step a clock's timers to the next event without a real-time wait.
+pub struct ReplayClock {
+    current_ns: AtomicU64,
+}
+
+impl ReplayClock {
+    /// Creates a new [`ReplayClock`] starting at `start_time_ns`.
+    #[must_use]
+    pub fn new(start_time_ns: UnixNanos) -> Self {
+        Self {
+            current_ns: AtomicU64::new(start_time_ns.as_u64()),
+        }
+    }
+
+    /// Advances the replay clock to `to_time_ns`, the timestamp of the next
+    /// piece of incoming data.
+    pub fn advance_to(&self, to_time_ns: UnixNanos) {
+        assert!(
+            to_time_ns.as_u64() >= self.current_ns.load(Ordering::Relaxed),
+            "`to_time_ns` was < current replay time"
+        );
+        self.current_ns.store(to_time_ns.as_u64(), Ordering::Relaxed);
+    }
+}
+
+impl TimeDriver for ReplayClock {
+    fn now_ns(&self) -> UnixNanos {
+        UnixNanos::from(self.current_ns.load(Ordering::Relaxed))
+    }
+
+    fn schedule_wake(&self, _at: UnixNanos) -> std::time::Duration {
+        // `Duration::ZERO` would claim "no delay" and make a LiveClock timer
+        // fire almost instantly in wall-clock time, rather than waiting for
+        // `advance_to`, which is the opposite of what a replay clock is for.
+        // There is no real-time delay this driver can correctly report, since
+        // firing only happens when `advance_to` is called, so return `MAX`
+        // to signal "never wakes via real-time scheduling" instead of
+        // silently claiming zero delay is a correct translation.
+        std::time::Duration::MAX
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nanos_duration_checked_sub_clamps_to_zero_when_alert_is_not_after_time() {
+        let time_ns = UnixNanos::from(100);
+        let alert_time_ns = UnixNanos::from(50);
+
+        let interval = NanosDuration::checked_sub(alert_time_ns, time_ns);
+
+        assert_eq!(interval.as_nanos(), 0);
+    }
+
+    #[test]
+    fn nanos_duration_checked_sub_returns_the_difference_when_alert_is_after_time() {
+        let time_ns = UnixNanos::from(100);
+        let alert_time_ns = UnixNanos::from(150);
+
+        let interval = NanosDuration::checked_sub(alert_time_ns, time_ns);
+
+        assert_eq!(interval.as_nanos(), 50);
+    }
+
+    #[test]
+    fn unix_nanos_add_nanos_duration_saturates_instead_of_overflowing() {
+        let time_ns = UnixNanos::from(u64::MAX - 1);
+        let interval = NanosDuration::from_nanos(10);
+
+        assert_eq!((time_ns + interval).as_u64(), u64::MAX);
+    }
+
+    #[test]
+    fn unix_nanos_sub_nanos_duration_saturates_instead_of_underflowing() {
+        let time_ns = UnixNanos::from(5);
+        let interval = NanosDuration::from_nanos(10);
+
+        assert_eq!((time_ns - interval).as_u64(), 0);
+    }
+
+    #[test]
+    fn peek_next_deadline_is_none_when_there_are_no_active_timers() {
+        let clock = TestClock::new();
+
+        assert_eq!(clock.peek_next_deadline(), None);
+    }
+
+    #[test]
+    fn advance_to_next_timer_is_a_no_op_when_there_are_no_active_timers() {
+        let mut clock = TestClock::new();
+
+        let events = clock.advance_to_next_timer();
+
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn detect_backward_jump_is_none_when_realtime_and_mono_advance_together() {
+        let mut clock = TestClock::new();
+        let last_realtime_ns = clock.timestamp_ns();
+        let last_mono_ns = clock.timestamp_mono_ns();
+
+        clock.advance_time(last_realtime_ns + NanosDuration::from_nanos(100), true);
+        clock.advance_mono_time(last_mono_ns + NanosDuration::from_nanos(100));
+
+        assert_eq!(clock.detect_backward_jump(last_realtime_ns, last_mono_ns), None);
+    }
+
+    #[test]
+    fn detect_backward_jump_is_some_when_realtime_falls_behind_mono() {
+        let mut clock = TestClock::new();
+        let last_realtime_ns = clock.timestamp_ns();
+        let last_mono_ns = clock.timestamp_mono_ns();
+
+        // Realtime stays put while monotonic time keeps advancing, as if an
+        // NTP step had pulled the wall clock backward relative to it.
+        clock.advance_mono_time(last_mono_ns + NanosDuration::from_nanos(100));
+
+        assert_eq!(
+            clock.detect_backward_jump(last_realtime_ns, last_mono_ns),
+            Some(-100)
+        );
+    }
+
+    #[test]
+    fn scaled_clock_new_rejects_non_finite_or_non_positive_speeds() {
+        assert!(ScaledClock::new(0.0).is_err());
+        assert!(ScaledClock::new(-1.0).is_err());
+        assert!(ScaledClock::new(f64::NAN).is_err());
+        assert!(ScaledClock::new(f64::INFINITY).is_err());
+        assert!(ScaledClock::new(2.0).is_ok());
+    }
+
+    #[test]
+    fn scaled_clock_set_speed_rejects_non_finite_or_non_positive_speeds() {
+        let clock = ScaledClock::new(1.0).unwrap();
+
+        assert!(clock.set_speed(0.0).is_err());
+        assert!(clock.set_speed(-1.0).is_err());
+        assert!(clock.set_speed(f64::NAN).is_err());
+        assert!(clock.set_speed(2.0).is_ok());
+    }
+
+    #[test]
+    fn scaled_clock_schedule_wake_returns_zero_for_an_already_due_wake() {
+        let clock = ScaledClock::new(1.0).unwrap();
+
+        let delay = clock.schedule_wake(UnixNanos::from(0));
+
+        assert_eq!(delay, std::time::Duration::ZERO);
+    }
+
+    #[test]
+    fn replay_clock_now_ns_tracks_advance_to() {
+        let clock = ReplayClock::new(UnixNanos::from(100));
+        assert_eq!(clock.now_ns(), UnixNanos::from(100));
+
+        clock.advance_to(UnixNanos::from(200));
+
+        assert_eq!(clock.now_ns(), UnixNanos::from(200));
+    }
+
+    #[test]
+    #[should_panic(expected = "current replay time")]
+    fn replay_clock_advance_to_panics_when_time_moves_backward() {
+        let clock = ReplayClock::new(UnixNanos::from(100));
+
+        clock.advance_to(UnixNanos::from(50));
+    }
+
+    #[test]
+    fn replay_clock_schedule_wake_never_fires_via_real_time_scheduling() {
+        let clock = ReplayClock::new(UnixNanos::from(100));
+
+        assert_eq!(
+            clock.schedule_wake(UnixNanos::from(200)),
+            std::time::Duration::MAX
+        );
+    }
+}